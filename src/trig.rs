@@ -214,6 +214,70 @@ impl<S: BaseFloat + Mul<S, S> + Div<S, S> + Rem<S, S>> Angle<S> {
             _ => fail!("Clock values cannot be unwrapped.")
         }
     }
+
+    /// Wraps a raw value into the half-open interval `[0, period)`.
+    fn wrap(v: S, period: S) -> S {
+        let v = v % period;
+        if v < Float::zero() { v + period } else { v }
+    }
+
+    /// Wraps a raw value into the half-open interval `[-period/2, period/2)`.
+    fn wrap_signed(v: S, period: S) -> S {
+        let half = period / FromPrimitive::from_f64(2.0).unwrap();
+        let v = Angle::wrap(v, period);
+        if v >= half { v - period } else { v }
+    }
+
+    /// Normalizes the angle into the canonical range `[0, full_turn)`,
+    /// preserving the receiver's unit.
+    pub fn normalize(&self) -> Angle<S> {
+        match self {
+            &Rad(val) => Angle::radians(Angle::wrap(val, Float::two_pi())),
+            &Deg(val) => Angle::degrees(Angle::wrap(val, FromPrimitive::from_f64(360.0).unwrap())),
+            &Grad(val) => Angle::gradians(Angle::wrap(val, FromPrimitive::from_f64(400.0).unwrap())),
+            &Turn(val) => Angle::turns(Angle::wrap(val, FromPrimitive::from_f64(1.0).unwrap())),
+            _ => unimplemented!()
+        }
+    }
+
+    /// Normalizes the angle into the signed range `[-half_turn, half_turn)`,
+    /// preserving the receiver's unit.
+    pub fn normalize_signed(&self) -> Angle<S> {
+        match self {
+            &Rad(val) => Angle::radians(Angle::wrap_signed(val, Float::two_pi())),
+            &Deg(val) => Angle::degrees(Angle::wrap_signed(val, FromPrimitive::from_f64(360.0).unwrap())),
+            &Grad(val) => Angle::gradians(Angle::wrap_signed(val, FromPrimitive::from_f64(400.0).unwrap())),
+            &Turn(val) => Angle::turns(Angle::wrap_signed(val, FromPrimitive::from_f64(1.0).unwrap())),
+            _ => unimplemented!()
+        }
+    }
+
+    /// Returns the angle diametrically opposite the receiver (adds half a
+    /// turn), normalized into `[0, full_turn)`.
+    pub fn opposite(&self) -> Angle<S> {
+        self.add(&Angle::half()).normalize()
+    }
+
+    /// Interpolates between the receiver and `other` along the shortest arc,
+    /// where `t = 0.0` returns the receiver and `t = 1.0` reaches the
+    /// direction of `other`. The result is in the receiver's unit and is not
+    /// normalized, so at `t = 1.0` it can differ from `other` by a whole turn
+    /// (from `10°` towards `350°` it ends at `-10°`).
+    pub fn lerp(&self, other: &Angle<S>, t: S) -> Angle<S> {
+        match other.sub(self).normalize_signed() {
+            Rad(val) => self.add(&Angle::radians(val * t)),
+            Deg(val) => self.add(&Angle::degrees(val * t)),
+            Grad(val) => self.add(&Angle::gradians(val * t)),
+            Turn(val) => self.add(&Angle::turns(val * t)),
+            _ => unimplemented!()
+        }
+    }
+
+    /// Returns the angle at the midpoint of the shortest arc between the
+    /// receiver and `other`.
+    pub fn bisect(&self, other: &Angle<S>) -> Angle<S> {
+        self.lerp(other, FromPrimitive::from_f64(0.5).unwrap())
+    }
 }
 
 impl<S: BaseFloat> Add<Angle<S>, Angle<S>> for Angle<S> {
@@ -294,4 +358,44 @@ mod test {
         assert_eq!(Angle::degrees(100.0f64) + Angle::radians(0.0f64), Angle::degrees(100.0f64));
         assert_eq!(Angle::radians(1.0f64) - Angle::degrees(0.0f64), Angle::radians(1.0f64));
     }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(Angle::degrees(-90.0f64).normalize(), Angle::degrees(270.0f64));
+        assert_eq!(Angle::degrees(450.0f64).normalize(), Angle::degrees(90.0f64));
+        assert_eq!(Angle::degrees(270.0f64).normalize_signed(), Angle::degrees(-90.0f64));
+        assert_eq!(Angle::degrees(90.0f64).normalize_signed(), Angle::degrees(90.0f64));
+
+        assert_eq!(Angle::gradians(-100.0f64).normalize(), Angle::gradians(300.0f64));
+        assert_eq!(Angle::gradians(300.0f64).normalize_signed(), Angle::gradians(-100.0f64));
+        assert_eq!(Angle::turns(-0.25f64).normalize(), Angle::turns(0.75f64));
+        assert_eq!(Angle::turns(0.75f64).normalize_signed(), Angle::turns(-0.25f64));
+    }
+
+    #[test]
+    fn test_opposite() {
+        assert_eq!(Angle::degrees(90.0f64).opposite(), Angle::degrees(270.0f64));
+        assert_eq!(Angle::degrees(0.0f64).opposite(), Angle::degrees(180.0f64));
+        assert_eq!(Angle::radians(Float::pi()).opposite(), Angle::radians(0.0f64));
+        assert_eq!(Angle::gradians(100.0f64).opposite(), Angle::gradians(300.0f64));
+    }
+
+    #[test]
+    fn test_bisect_and_lerp() {
+        assert_eq!(Angle::degrees(0.0f64).bisect(&Angle::degrees(90.0f64)), Angle::degrees(45.0f64));
+        assert_eq!(Angle::degrees(350.0f64).bisect(&Angle::degrees(10.0f64)), Angle::degrees(0.0f64));
+        assert_eq!(Angle::degrees(0.0f64).lerp(&Angle::degrees(90.0f64), 0.0f64), Angle::degrees(0.0f64));
+        assert_eq!(Angle::degrees(0.0f64).lerp(&Angle::degrees(90.0f64), 1.0f64), Angle::degrees(90.0f64));
+
+        assert_eq!(Angle::turns(0.0f64).bisect(&Angle::turns(0.25f64)), Angle::turns(0.125f64));
+
+        // 200 gradians is exactly half the 400-gradian period, so this is an
+        // exactly-opposite tie-break: `wrap_signed`'s `[-half_turn, half_turn)`
+        // contract routes it to the negative boundary, making the shortest arc
+        // go the "other way" around from what a naive +100.0 might expect.
+        assert_eq!(Angle::gradians(0.0f64).lerp(&Angle::gradians(200.0f64), 0.5f64), Angle::gradians(-100.0f64));
+
+        // Mixed-unit lerp: 90° in radians halved should land back at 45°.
+        assert_eq!(Angle::degrees(0.0f64).lerp(&Angle::radians(Float::frac_pi_2()), 0.5f64), Angle::degrees(45.0f64));
+    }
 }